@@ -0,0 +1,450 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Formatter, Result},
+    sync::Arc,
+};
+
+use crate::redelmeier::{self, Stack};
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct Coord(pub i32, pub i32);
+
+/// A polyomino packed as a bitmask over its bounding box: bit `x + width*y`
+/// is set exactly when cell `(x, y)` is occupied, stored as a flat `Vec<u64>`
+/// of words (word `i` holds bits `64*i..64*i+64`) rather than a single fixed
+/// integer, so a lopsided bounding box (`width*height` past 64 or 128 bits)
+/// packs correctly instead of overflowing. The word vector is wrapped in an
+/// `Arc` so that passing one around (as `canonize_free` does for each of the
+/// 8 dihedral images, or as the free map keeps doing while it's compared and
+/// sorted) is a cheap pointer clone rather than a full buffer copy; only a
+/// genuinely new mask (built by `from_coords`, or by transforming bits in
+/// `rotate`/`transpose`) materializes a fresh one. `width`/`height` are
+/// always the shape's tight bounding box, so a freshly-built `Polyomino` is
+/// already shifted to the top-left corner.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct Polyomino {
+    bits: Arc<[u64]>,
+    width: i32,
+    height: i32,
+}
+
+/// Number of `u64` words needed to hold `bit_count` bits.
+fn words_for(bit_count: i32) -> usize {
+    (bit_count as usize).div_ceil(64)
+}
+
+fn get_bit(bits: &[u64], index: i32) -> bool {
+    let word = index as usize / 64;
+    word < bits.len() && bits[word] & (1u64 << (index as usize % 64)) != 0
+}
+
+fn set_bit(bits: &mut [u64], index: i32) {
+    bits[index as usize / 64] |= 1u64 << (index as usize % 64);
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PolyominoSymmetryGroup {
+    None,
+    Mirror90,
+    Mirror45,
+    Rotation2Fold,
+    Rotation2FoldMirror90,
+    Rotation2FoldMirror45,
+    Rotation4Fold,
+    All,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum OneSidedSymmetryGroup {
+    None,
+    Rotation2Fold,
+    Rotation4Fold,
+}
+
+/// Which of the three standard polyomino countings to emit: `Fixed` keeps
+/// every translation class as its own entry, `OneSided` dedupes only across
+/// the four rotations, `Free` (the default) dedupes across the full dihedral
+/// group. These correspond to OEIS A001168, A000988, and A000105.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum EnumerationMode {
+    Fixed,
+    OneSided,
+    Free,
+}
+
+impl EnumerationMode {
+    pub fn parse(s: &str) -> EnumerationMode {
+        match s {
+            "fixed" => EnumerationMode::Fixed,
+            "one-sided" => EnumerationMode::OneSided,
+            "free" => EnumerationMode::Free,
+            _ => panic!("unknown enumeration mode `{}` (expected fixed, one-sided, or free)", s),
+        }
+    }
+}
+
+impl Coord {
+    fn neighbors(self) -> [Coord; 4] {
+        let Coord(x, y) = self;
+        [
+            Coord(x + 1, y),
+            Coord(x - 1, y),
+            Coord(x, y + 1),
+            Coord(x, y - 1),
+        ]
+    }
+}
+
+impl Polyomino {
+    /// Packs a coordinate list into a bitboard, shifted so its bounding box
+    /// starts at the origin (i.e. already in `canonize_fixed` form).
+    fn from_coords(cells: &[Coord]) -> Polyomino {
+        let min_x = cells.iter().map(|coord| coord.0).min().unwrap_or(0);
+        let min_y = cells.iter().map(|coord| coord.1).min().unwrap_or(0);
+        let width = cells.iter().map(|coord| coord.0).max().unwrap_or(0) - min_x + 1;
+        let height = cells.iter().map(|coord| coord.1).max().unwrap_or(0) - min_y + 1;
+
+        let mut bits = vec![0u64; words_for(width * height)];
+        for &Coord(x, y) in cells {
+            set_bit(&mut bits, (x - min_x) + width * (y - min_y));
+        }
+
+        Polyomino { bits: Arc::from(bits), width, height }
+    }
+
+    /// Unpacks the bitboard back into its coordinate list, for `Display`/JSON
+    /// output and for the metrics/rendering that still reason cell-by-cell.
+    fn coords(&self) -> Vec<Coord> {
+        (0..self.width * self.height)
+            .filter(|&bit| get_bit(&self.bits, bit))
+            .map(|bit| Coord(bit % self.width, bit / self.width))
+            .collect()
+    }
+
+    /// Shifts the mask to its tight top-left-anchored bounding box. A
+    /// `Polyomino` built via [`Polyomino::from_coords`] (every `Polyomino`
+    /// this crate produces) is already in this form, so this is just a cheap
+    /// `Arc` clone of the existing buffer rather than a decode-and-repack.
+    fn canonize_fixed(&self) -> Polyomino {
+        self.clone()
+    }
+
+    /// Rotates the mask 90 degrees by remapping each occupied `(x, y)` to
+    /// `(height-1-y, x)` in a `height`-by-`width` board. Since the source
+    /// mask is already a tight bounding box, the result is too; no separate
+    /// canonicalization pass is needed.
+    fn rotate(&self) -> Polyomino {
+        let (width, height) = (self.width, self.height);
+        let mut bits = vec![0u64; words_for(width * height)];
+
+        for y in 0..height {
+            for x in 0..width {
+                if get_bit(&self.bits, x + width * y) {
+                    set_bit(&mut bits, (height - 1 - y) + height * x);
+                }
+            }
+        }
+
+        Polyomino { bits: Arc::from(bits), width: height, height: width }
+    }
+
+    /// Reflects the mask across its main diagonal by remapping each occupied
+    /// `(x, y)` to `(y, x)` in a `height`-by-`width` board.
+    fn transpose(&self) -> Polyomino {
+        let (width, height) = (self.width, self.height);
+        let mut bits = vec![0u64; words_for(width * height)];
+
+        for y in 0..height {
+            for x in 0..width {
+                if get_bit(&self.bits, x + width * y) {
+                    set_bit(&mut bits, y + height * x);
+                }
+            }
+        }
+
+        Polyomino { bits: Arc::from(bits), width: height, height: width }
+    }
+
+    fn canonize_free(&self) -> (Polyomino, PolyominoSymmetryGroup) {
+        let c0 = self.canonize_fixed();
+        let c90 = self.rotate();
+        let c180 = c90.rotate();
+        let c270 = c180.rotate();
+        let t0 = self.transpose();
+        let t90 = t0.rotate();
+        let t180 = t90.rotate();
+        let t270 = t180.rotate();
+
+        let symmetry_group = if c0 == c90 {
+            if c0 == t0 {
+                PolyominoSymmetryGroup::All
+            } else {
+                PolyominoSymmetryGroup::Rotation4Fold
+            }
+        } else if c0 == t0 || c0 == t180 {
+            if c0 == c180 {
+                PolyominoSymmetryGroup::Rotation2FoldMirror45
+            } else {
+                PolyominoSymmetryGroup::Mirror45
+            }
+        } else if c0 == t90 || c0 == t270 {
+            if c0 == c180 {
+                PolyominoSymmetryGroup::Rotation2FoldMirror90
+            } else {
+                PolyominoSymmetryGroup::Mirror90
+            }
+        } else if c0 == c180 {
+            PolyominoSymmetryGroup::Rotation2Fold
+        } else {
+            PolyominoSymmetryGroup::None
+        };
+
+        let mut all = vec![c0, c90, c180, c270, t0, t90, t180, t270];
+        all.sort();
+
+        (all.remove(0), symmetry_group)
+    }
+
+    /// Canonicalizes under the four rotations only (no reflection), yielding
+    /// one representative per one-sided orbit.
+    fn canonize_one_sided(&self) -> (Polyomino, OneSidedSymmetryGroup) {
+        let c0 = self.canonize_fixed();
+        let c90 = self.rotate();
+        let c180 = c90.rotate();
+        let c270 = c180.rotate();
+
+        let symmetry_group = if c0 == c90 {
+            OneSidedSymmetryGroup::Rotation4Fold
+        } else if c0 == c180 {
+            OneSidedSymmetryGroup::Rotation2Fold
+        } else {
+            OneSidedSymmetryGroup::None
+        };
+
+        let mut all = vec![c0, c90, c180, c270];
+        all.sort();
+
+        (all.remove(0), symmetry_group)
+    }
+
+    /// Builds the JSON object recorded for this polyomino: its `symmetry`
+    /// label plus bounding-box width/height, cell count, perimeter (edges
+    /// bordering empty space), and whether it encloses a hole.
+    fn describe(&self, symmetry: &str) -> String {
+        let cells = self.coords();
+        let perimeter: usize = cells
+            .iter()
+            .map(|&cell| {
+                4 - cell
+                    .neighbors()
+                    .into_iter()
+                    .filter(|neighbor| cells.contains(neighbor))
+                    .count()
+            })
+            .sum();
+
+        format!(
+            "{{\"symmetry\":\"{}\",\"width\":{},\"height\":{},\"cells\":{},\"perimeter\":{},\"hasHole\":{}}}",
+            symmetry,
+            self.width,
+            self.height,
+            cells.len(),
+            perimeter,
+            has_hole(&cells, self.width, self.height),
+        )
+    }
+
+    /// Renders this polyomino's bounding box as `#`/`.` grid art, one row of
+    /// the bounding box per line.
+    fn render(&self) -> String {
+        (0..self.height)
+            .map(|y| {
+                (0..self.width)
+                    .map(|x| if get_bit(&self.bits, x + self.width * y) { '#' } else { '.' })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Flood-fills the complement of `cells` within their bounding box (`width`
+/// by `height`) padded by one cell of border, starting from the border;
+/// any empty cell the flood can't reach is enclosed by the shape, i.e. the
+/// shape has a hole.
+fn has_hole(cells: &[Coord], width: i32, height: i32) -> bool {
+    let mut visited = vec![Coord(-1, -1)];
+    let mut frontier = vec![Coord(-1, -1)];
+
+    while let Some(c) = frontier.pop() {
+        for neighbor in c.neighbors() {
+            let Coord(x, y) = neighbor;
+            if x < -1 || y < -1 || x > width || y > height {
+                continue;
+            }
+            if cells.contains(&neighbor) || visited.contains(&neighbor) {
+                continue;
+            }
+            visited.push(neighbor);
+            frontier.push(neighbor);
+        }
+    }
+
+    let padded_area = (width + 2) * (height + 2);
+    let total_empty = padded_area as usize - cells.len();
+    total_empty > visited.len()
+}
+
+impl Display for Coord {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{},{}", self.0, self.1)
+    }
+}
+
+impl Display for Polyomino {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let mut coords = self.coords();
+        coords.sort();
+
+        for (i, coord) in coords.iter().enumerate() {
+            if i != 0 {
+                write!(f, ",")?;
+            }
+            coord.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Index used to totally order cells for Redelmeier's algorithm: a half-plane
+/// of width `2n-1` rooted so that the origin (the root cell every search
+/// starts from) has the smallest index any reachable cell can have.
+fn cell_index(width: i32, Coord(x, y): Coord) -> i64 {
+    x as i64 + width as i64 * y as i64
+}
+
+/// Enumerates every fixed polyomino of size `n` (i.e. every distinct cell
+/// set, with no canonicalization across rotation/reflection) exactly once,
+/// using Redelmeier's recursive generator (see [`redelmeier::generate_fixed`]).
+pub fn enumerate_fixed(n: usize) -> Vec<Polyomino> {
+    if n == 0 {
+        return vec![];
+    }
+
+    let width = 2 * n as i32 - 1;
+    let root = Coord(0, 0);
+    let root_index = cell_index(width, root);
+
+    let mut results = Vec::new();
+    redelmeier::generate_fixed(
+        n,
+        root_index,
+        &mut Vec::with_capacity(n),
+        &Stack::new().push(root),
+        &Stack::new(),
+        &|c| cell_index(width, c),
+        &Coord::neighbors,
+        &mut |cells| results.push(Polyomino::from_coords(cells)),
+    );
+    results
+}
+
+/// Enumerates every free polyomino of size `n` by canonicalizing each fixed
+/// polyomino from [`enumerate_fixed`] and keeping one representative per
+/// symmetry orbit.
+pub fn enumerate_free(n: usize) -> BTreeMap<Polyomino, PolyominoSymmetryGroup> {
+    enumerate_fixed(n)
+        .into_iter()
+        .map(|fixed| fixed.canonize_free())
+        .collect()
+}
+
+/// Enumerates polyominoes of size `n` under the requested `mode`, labeling
+/// each representative with its symmetry group and bounding-box/hole
+/// metadata (see [`Polyomino::describe`]).
+pub fn enumerate(mode: EnumerationMode, n: usize) -> BTreeMap<Polyomino, String> {
+    match mode {
+        EnumerationMode::Fixed => enumerate_fixed(n)
+            .into_iter()
+            .map(|fixed| {
+                let canon = fixed.canonize_fixed();
+                let description = canon.describe("Fixed");
+                (canon, description)
+            })
+            .collect(),
+        EnumerationMode::OneSided => enumerate_fixed(n)
+            .into_iter()
+            .map(|fixed| {
+                let (canon, symmetry_group) = fixed.canonize_one_sided();
+                let description = canon.describe(&format!("{:?}", symmetry_group));
+                (canon, description)
+            })
+            .collect(),
+        EnumerationMode::Free => enumerate_free(n)
+            .into_iter()
+            .map(|(polyomino, symmetry_group)| {
+                let description = polyomino.describe(&format!("{:?}", symmetry_group));
+                (polyomino, description)
+            })
+            .collect(),
+    }
+}
+
+/// Renders every free polyomino of size `n` as `#`/`.` grid art labeled by
+/// its `PolyominoSymmetryGroup`, for a human-verifiable text dump of a
+/// size's free polyominoes.
+pub fn render_free(n: usize) -> String {
+    enumerate_free(n)
+        .into_iter()
+        .map(|(polyomino, symmetry_group)| format!("{:?}\n{}", symmetry_group, polyomino.render()))
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// OEIS A001168: fixed polyominoes.
+    #[test]
+    fn fixed_counts_match_oeis_a001168() {
+        let counts: Vec<usize> = (1..=9).map(|n| enumerate_fixed(n).len()).collect();
+        assert_eq!(counts, vec![1, 2, 6, 19, 63, 216, 760, 2725, 9910]);
+    }
+
+    /// OEIS A000105: free polyominoes.
+    #[test]
+    fn free_counts_match_oeis_a000105() {
+        let counts: Vec<usize> = (1..=9).map(|n| enumerate_free(n).len()).collect();
+        assert_eq!(counts, vec![1, 1, 2, 5, 12, 35, 108, 369, 1285]);
+    }
+
+    /// OEIS A000988: one-sided polyominoes.
+    #[test]
+    fn one_sided_counts_match_oeis_a000988() {
+        let counts: Vec<usize> =
+            (1..=8).map(|n| enumerate(EnumerationMode::OneSided, n).len()).collect();
+        assert_eq!(counts, vec![1, 1, 2, 7, 18, 60, 196, 704]);
+    }
+
+    /// OEIS A001419: the first free polyomino with a hole appears at n=7
+    /// (a single orbit, the classic "holed heptomino"); the analogous fixed
+    /// count is that orbit's size, 4, under the dihedral group.
+    #[test]
+    fn hole_detection_matches_oeis_a001419() {
+        let free_with_holes = enumerate_free(7)
+            .into_iter()
+            .filter(|(polyomino, _)| has_hole(&polyomino.coords(), polyomino.width, polyomino.height))
+            .count();
+        assert_eq!(free_with_holes, 1);
+
+        let fixed_with_holes = enumerate_fixed(7)
+            .into_iter()
+            .filter(|polyomino| has_hole(&polyomino.coords(), polyomino.width, polyomino.height))
+            .count();
+        assert_eq!(fixed_with_holes, 4);
+
+        let smaller = &enumerate_fixed(6)[0];
+        assert!(!has_hole(&smaller.coords(), smaller.width, smaller.height));
+    }
+}
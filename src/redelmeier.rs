@@ -0,0 +1,97 @@
+use std::rc::Rc;
+
+struct StackNode<T> {
+    value: T,
+    rest: Stack<T>,
+}
+
+/// A persistent singly-linked stack: pushing shares the existing tail behind
+/// an `Rc` instead of copying it. Handing a recursive branch its own
+/// `untried`/`forbidden` is then an O(1) pointer clone plus the handful of
+/// new candidates it discovered, rather than an O(n) `Vec` clone repeated at
+/// every node of the search.
+pub struct Stack<T>(Option<Rc<StackNode<T>>>);
+
+impl<T> Stack<T> {
+    pub fn new() -> Stack<T> {
+        Stack(None)
+    }
+
+    pub fn push(&self, value: T) -> Stack<T> {
+        Stack(Some(Rc::new(StackNode { value, rest: self.clone() })))
+    }
+}
+
+impl<T> Clone for Stack<T> {
+    fn clone(&self) -> Stack<T> {
+        Stack(self.0.clone())
+    }
+}
+
+impl<T: Copy> Stack<T> {
+    fn pop(&self) -> Option<(T, Stack<T>)> {
+        self.0.as_ref().map(|node| (node.value, node.rest.clone()))
+    }
+}
+
+impl<T: PartialEq> Stack<T> {
+    fn contains(&self, target: &T) -> bool {
+        let mut current = self;
+        while let Some(node) = &current.0 {
+            if node.value == *target {
+                return true;
+            }
+            current = &node.rest;
+        }
+        false
+    }
+}
+
+/// Recursive step of Redelmeier's algorithm, generic over the cell type `T`
+/// and its `NEIGHBORS`-wide neighborhood so 2D and 3D (and any future
+/// dimension) share one implementation: grow `cells` by popping candidates
+/// off `untried`, calling `on_complete` for every completed shape of size
+/// `n`, and marking each tried cell `forbidden` once its branch is exhausted
+/// so siblings never reuse it. Each candidate is explored against its own
+/// extension of `untried`/`forbidden`, so a deeper branch can freely draw on
+/// any cell still open at that point without disturbing what this level
+/// sees once the branch returns and the loop moves on to the next candidate.
+#[allow(clippy::too_many_arguments)]
+pub fn generate_fixed<T: Copy + PartialEq, const NEIGHBORS: usize>(
+    n: usize,
+    root_index: i64,
+    cells: &mut Vec<T>,
+    untried: &Stack<T>,
+    forbidden: &Stack<T>,
+    cell_index: &impl Fn(T) -> i64,
+    neighbors: &impl Fn(T) -> [T; NEIGHBORS],
+    on_complete: &mut impl FnMut(&[T]),
+) {
+    let mut untried = untried.clone();
+    let mut forbidden = forbidden.clone();
+
+    while let Some((c, rest)) = untried.pop() {
+        cells.push(c);
+
+        if cells.len() == n {
+            on_complete(cells);
+        } else {
+            let mut child_untried = rest.clone();
+            for neighbor in neighbors(c) {
+                if cell_index(neighbor) > root_index
+                    && !cells.contains(&neighbor)
+                    && !child_untried.contains(&neighbor)
+                    && !forbidden.contains(&neighbor)
+                {
+                    child_untried = child_untried.push(neighbor);
+                }
+            }
+
+            generate_fixed(n, root_index, cells, &child_untried, &forbidden, cell_index, neighbors, on_complete);
+        }
+
+        cells.pop();
+        forbidden = forbidden.push(c);
+        untried = rest;
+    }
+}